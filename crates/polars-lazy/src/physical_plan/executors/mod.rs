@@ -0,0 +1,3 @@
+mod union;
+
+pub use union::UnionExec;
@@ -0,0 +1,49 @@
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+
+/// Executes a `Union`: runs every input and concatenates the results against the
+/// coerced schema produced by `LogicalPlan::schema()`'s `Union` arm (see
+/// `polars_plan::logical_plan::schema::union_schema`). Branches whose columns were
+/// widened to a common supertype (e.g. an `Int32` branch alongside an `Int64` one) are
+/// cast to that schema here, so the concatenated output is actually uniform and not just
+/// reported as such.
+pub struct UnionExec {
+    pub(crate) inputs: Vec<Box<dyn Executor>>,
+    pub(crate) options: UnionOptions,
+    /// The coerced schema every input must be cast to before concatenation.
+    pub(crate) schema: SchemaRef,
+}
+
+impl Executor for UnionExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let mut frames = Vec::with_capacity(self.inputs.len());
+        for input in &mut self.inputs {
+            let mut df = input.execute(state)?;
+            cast_to_union_schema(&mut df, &self.schema)?;
+            frames.push(df);
+        }
+        let mut out = accumulate_dataframes_vertical(frames, true)?;
+        if let Some((offset, len)) = self.options.slice {
+            out = out.slice(offset, len);
+        }
+        Ok(out)
+    }
+}
+
+/// Casts every column of `df` that doesn't already match `schema`'s dtype for that
+/// position, in place. Positional, mirroring how `union_schema` coerced the dtypes.
+fn cast_to_union_schema(df: &mut DataFrame, schema: &Schema) -> PolarsResult<()> {
+    polars_ensure!(
+        df.width() == schema.len(),
+        ComputeError: "union branch has {} columns, expected {}", df.width(), schema.len()
+    );
+    for (s, (_, dtype)) in df.get_columns_mut().iter_mut().zip(schema.iter()) {
+        if s.dtype() != dtype {
+            *s = s.cast(dtype)?;
+        }
+    }
+    Ok(())
+}
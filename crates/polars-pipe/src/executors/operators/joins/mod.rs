@@ -0,0 +1,3 @@
+mod symmetric;
+
+pub use symmetric::{should_use_symmetric_join, IntervalBounds, JoinSide, SymmetricJoinOperator};
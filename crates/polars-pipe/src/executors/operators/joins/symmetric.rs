@@ -0,0 +1,513 @@
+use std::collections::VecDeque;
+
+use polars_core::prelude::*;
+use polars_utils::format_smartstring;
+
+use crate::operators::DataChunk;
+
+/// Which side of the join a batch belongs to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum JoinSide {
+    Left,
+    Right,
+}
+
+/// Bounds for an interval join predicate of the shape
+/// `left.ordered_on BETWEEN right.ordered_on - lower AND right.ordered_on + upper`.
+///
+/// The bound is expressed in "ticks" of the ordered column so it works for both
+/// integer and (already-cast-to-int64) temporal columns.
+#[derive(Clone, Debug)]
+pub struct IntervalBounds {
+    pub lower: i64,
+    pub upper: i64,
+}
+
+impl IntervalBounds {
+    /// The oldest value on the opposite side that can still match a probe row from
+    /// `probe_side` with ordered-column value `ts`, given that the opposite side's ordered
+    /// column is increasing. Rows older than this can be evicted from that side's build table.
+    fn low_watermark_for(&self, probe_side: JoinSide, ts: i64) -> i64 {
+        match probe_side {
+            // A right row can still match while `right + upper >= left`.
+            JoinSide::Left => ts.saturating_sub(self.upper),
+            // A left row can still match while `left >= right - lower`.
+            JoinSide::Right => ts.saturating_sub(self.lower),
+        }
+    }
+}
+
+/// One row kept in a side's build table: the batch it came from plus its row index (so
+/// it can be materialized later), the row's actual join key values (re-checked on probe
+/// to rule out hash collisions and to give NULL keys correct "never matches" semantics),
+/// its ordered-column value (if interval pruning is enabled), and whether it has ever
+/// produced a match. The match flag lets Left/Right/Outer variants flush the unmatched
+/// remainder exactly once, when a side is known to be exhausted or a row has aged out of
+/// the interval.
+struct BuildRow {
+    chunk: DataChunk,
+    row_idx: IdxSize,
+    keys: Vec<AnyValue<'static>>,
+    ordered_value: Option<i64>,
+    matched: bool,
+}
+
+impl BuildRow {
+    fn has_null_key(&self) -> bool {
+        self.keys.iter().any(|v| matches!(v, AnyValue::Null))
+    }
+}
+
+/// SQL equi-join semantics: a NULL key never equals anything, including another NULL.
+fn keys_match(a: &[AnyValue], b: &[AnyValue]) -> bool {
+    a.iter().zip(b).all(|(x, y)| {
+        !matches!(x, AnyValue::Null) && !matches!(y, AnyValue::Null) && x == y
+    })
+}
+
+/// Per-side hash table: join key hash -> build rows sharing that hash, kept in arrival
+/// order so pruning can cheaply pop from the front once the watermark passes them. The
+/// hash is only a pre-filter; matches are confirmed against `BuildRow::keys`.
+#[derive(Default)]
+struct BuildSide {
+    table: PlHashMap<u64, VecDeque<BuildRow>>,
+    /// Monotonically increasing watermark: the largest ordered-column value seen so far
+    /// on *this* side. Used to derive how far the opposite side may be pruned.
+    watermark: Option<i64>,
+    /// Set once the upstream pipeline reports no more batches will arrive for this side.
+    exhausted: bool,
+}
+
+impl BuildSide {
+    fn insert(&mut self, hash: u64, row: BuildRow) {
+        if let Some(v) = row.ordered_value {
+            self.watermark = Some(self.watermark.map_or(v, |w| w.max(v)));
+        }
+        self.table.entry(hash).or_default().push_back(row);
+    }
+}
+
+/// A symmetric hash join operator for streaming execution: unlike the blocking hash join
+/// (which fully materializes one side before probing), this operator maintains a hash
+/// table on *both* sides. Every incoming batch probes the opposite side's table first,
+/// then is inserted into its own table, so two unbounded, ordered streams can be joined
+/// without ever fully materializing either one.
+///
+/// Memory is bounded via an optional interval predicate on an ordered column (e.g.
+/// `left.ts BETWEEN right.ts - d1 AND right.ts + d2`): as each side's monotonically
+/// increasing ordered column advances, rows on the *opposite* side that can no longer
+/// satisfy the interval are evicted from that side's build table.
+///
+/// This type exposes a two-port API (`probe_left`/`probe_right`/`mark_*_exhausted`)
+/// rather than the single-input `Operator` trait, since a streaming join genuinely has
+/// two independent input streams to drive; the streaming lowering is expected to wire
+/// each input's pipeline branch to the matching method, the same way a binary `Sink`
+/// is driven from two ports.
+pub struct SymmetricJoinOperator {
+    how: JoinType,
+    left_on: Vec<usize>,
+    right_on: Vec<usize>,
+    left_on_names: Vec<SmartString>,
+    right_on_names: Vec<SmartString>,
+    /// `Some` when the two sides carry an ordered column eligible for interval pruning.
+    ordered_on: Option<(usize, usize)>,
+    bounds: Option<IntervalBounds>,
+    left: BuildSide,
+    right: BuildSide,
+    left_schema: SchemaRef,
+    right_schema: SchemaRef,
+    suffix: String,
+}
+
+impl SymmetricJoinOperator {
+    pub fn new(
+        how: JoinType,
+        left_on: Vec<usize>,
+        right_on: Vec<usize>,
+        ordered_on: Option<(usize, usize)>,
+        bounds: Option<IntervalBounds>,
+        left_schema: SchemaRef,
+        right_schema: SchemaRef,
+        suffix: String,
+    ) -> Self {
+        let left_on_names = on_names(&left_schema, &left_on);
+        let right_on_names = on_names(&right_schema, &right_on);
+        Self {
+            how,
+            left_on,
+            right_on,
+            left_on_names,
+            right_on_names,
+            ordered_on,
+            bounds,
+            left: BuildSide::default(),
+            right: BuildSide::default(),
+            left_schema,
+            right_schema,
+            suffix,
+        }
+    }
+
+    pub fn split(&self) -> Self {
+        // The build tables are shared join state, not per-thread partitions of
+        // independent work, so a clone of the (currently empty) operator shape is
+        // handed out and the pipeline is expected to route all batches for a given
+        // key partition to the same instance, as the generic hash-join build does.
+        Self::new(
+            self.how.clone(),
+            self.left_on.clone(),
+            self.right_on.clone(),
+            self.ordered_on,
+            self.bounds.clone(),
+            self.left_schema.clone(),
+            self.right_schema.clone(),
+            self.suffix.clone(),
+        )
+    }
+
+    fn hash_keys(df: &DataFrame, on: &[usize]) -> PolarsResult<Vec<u64>> {
+        let cols = on
+            .iter()
+            .map(|i| df.get_columns()[*i].clone())
+            .collect::<Vec<_>>();
+        let keys = DataFrame::new_no_checks(cols);
+        Ok(keys.hash_rows(None)?.into_no_null_iter().collect())
+    }
+
+    fn row_keys(df: &DataFrame, on: &[usize], row_idx: usize) -> PolarsResult<Vec<AnyValue<'static>>> {
+        on.iter()
+            .map(|&i| Ok(df.get_columns()[i].get(row_idx)?.into_static()))
+            .collect()
+    }
+
+    fn ordered_values(df: &DataFrame, idx: Option<usize>) -> PolarsResult<Vec<Option<i64>>> {
+        match idx {
+            None => Ok(vec![None; df.height()]),
+            Some(idx) => {
+                let s = &df.get_columns()[idx];
+                let s = s.cast(&DataType::Int64)?;
+                let ca = s.i64()?;
+                Ok(ca.into_iter().collect())
+            },
+        }
+    }
+
+    /// Probes an incoming batch from the left side against the right table, then inserts
+    /// it into the left table. Returns any newly matched row pairs.
+    pub fn probe_left(&mut self, chunk: DataChunk) -> PolarsResult<Vec<DataFrame>> {
+        self.probe_and_insert(JoinSide::Left, chunk)
+    }
+
+    /// Probes an incoming batch from the right side against the left table, then inserts
+    /// it into the right table. Returns any newly matched row pairs.
+    pub fn probe_right(&mut self, chunk: DataChunk) -> PolarsResult<Vec<DataFrame>> {
+        self.probe_and_insert(JoinSide::Right, chunk)
+    }
+
+    /// Probe `incoming` against the opposite side's table, emitting matched row pairs,
+    /// then prune the opposite side using the watermark this batch just advanced (if
+    /// interval bounds apply), and finally insert `incoming`'s rows into `side`'s own
+    /// table.
+    fn probe_and_insert(&mut self, side: JoinSide, chunk: DataChunk) -> PolarsResult<Vec<DataFrame>> {
+        let df = chunk.data.clone();
+        let (on, ordered_idx) = match side {
+            JoinSide::Left => (self.left_on.clone(), self.ordered_on.map(|(l, _)| l)),
+            JoinSide::Right => (self.right_on.clone(), self.ordered_on.map(|(_, r)| r)),
+        };
+        let hashes = Self::hash_keys(&df, &on)?;
+        let ordered = Self::ordered_values(&df, ordered_idx)?;
+
+        let mut out = Vec::new();
+        // Tracks whether each of *this batch's own* rows found a match during this
+        // probe pass, so that row's `BuildRow` (inserted into `side`'s own table just
+        // below) starts with the correct `matched` flag instead of always `false` --
+        // otherwise a row that matches on arrival gets flushed again, unmatched and
+        // null-padded, once it later ages out or its side is drained.
+        let mut probe_matched = vec![false; hashes.len()];
+        for (row_idx, (hash, ts)) in hashes.iter().zip(ordered.iter()).enumerate() {
+            let probe_keys = Self::row_keys(&df, &on, row_idx)?;
+            let probe_has_null = probe_keys.iter().any(|v| matches!(v, AnyValue::Null));
+
+            if !probe_has_null {
+                let other = match side {
+                    JoinSide::Left => &mut self.right,
+                    JoinSide::Right => &mut self.left,
+                };
+                if let Some(rows) = other.table.get_mut(hash) {
+                    for build_row in rows.iter_mut() {
+                        if build_row.has_null_key() || !keys_match(&probe_keys, &build_row.keys) {
+                            continue;
+                        }
+                        build_row.matched = true;
+                        probe_matched[row_idx] = true;
+                        out.push(self.materialize_pair(side, &df, row_idx as IdxSize, build_row)?);
+                    }
+                }
+            }
+
+            // Advance the opposite side's pruning watermark using this side's own
+            // watermark + interval bounds, then evict (and, for outer-ish joins, flush)
+            // rows that can no longer match.
+            if let (Some(bounds), Some(ts)) = (&self.bounds, ts) {
+                let own_watermark = match side {
+                    JoinSide::Left => self.left.watermark,
+                    JoinSide::Right => self.right.watermark,
+                }
+                .map_or(*ts, |w| w.max(*ts));
+                let min_relevant = bounds.low_watermark_for(side, own_watermark);
+                let opposite = match side {
+                    JoinSide::Left => JoinSide::Right,
+                    JoinSide::Right => JoinSide::Left,
+                };
+                out.extend(self.prune_and_flush(opposite, min_relevant)?);
+            }
+        }
+
+        let self_side = match side {
+            JoinSide::Left => &mut self.left,
+            JoinSide::Right => &mut self.right,
+        };
+        for (row_idx, (hash, ts)) in hashes.into_iter().zip(ordered).enumerate() {
+            let keys = Self::row_keys(&df, &on, row_idx)?;
+            self_side.insert(
+                hash,
+                BuildRow {
+                    chunk: chunk.clone(),
+                    row_idx: row_idx as IdxSize,
+                    keys,
+                    ordered_value: ts,
+                    matched: probe_matched[row_idx],
+                },
+            );
+        }
+        Ok(out)
+    }
+
+    fn materialize_pair(
+        &self,
+        probe_side: JoinSide,
+        probe_df: &DataFrame,
+        probe_row: IdxSize,
+        build_row: &BuildRow,
+    ) -> PolarsResult<DataFrame> {
+        let probe_row_df = probe_df.take(&[probe_row].into());
+        let build_row_df = build_row.chunk.data.take(&[build_row.row_idx].into());
+        match probe_side {
+            JoinSide::Left => {
+                let build_row_df = self.drop_merged_right_on(build_row_df)?;
+                hstack_suffixed(probe_row_df, build_row_df, &self.suffix)
+            },
+            JoinSide::Right => {
+                let probe_row_df = self.drop_merged_right_on(probe_row_df)?;
+                hstack_suffixed(build_row_df, probe_row_df, &self.suffix)
+            },
+        }
+    }
+
+    /// Pads an unmatched build row (from `which` side) with a null-filled opposite side
+    /// and orders the columns left-then-right, matching `materialize_pair`'s and
+    /// `det_join_schema`'s output shape (which drops the right-hand equi-join keys and
+    /// merges them into the left-hand ones).
+    fn pad_with_nulls(&self, which: JoinSide, row: &BuildRow) -> PolarsResult<DataFrame> {
+        let row_df = row.chunk.data.take(&[row.row_idx].into());
+        match which {
+            JoinSide::Left => {
+                let nulls = null_frame(&self.right_schema, row_df.height());
+                let nulls = self.drop_merged_right_on(nulls)?;
+                hstack_suffixed(row_df, nulls, &self.suffix)
+            },
+            JoinSide::Right => {
+                let mut nulls = null_frame(&self.left_schema, row_df.height());
+                if self.merges_join_keys() {
+                    // The merged column only exists under the left side's name, so an
+                    // unmatched right row's key -- which would otherwise vanish along
+                    // with the dropped right_on columns below -- is coalesced into that
+                    // slot instead of being left null.
+                    for (left_name, right_name) in
+                        self.left_on_names.iter().zip(&self.right_on_names)
+                    {
+                        let mut value = row_df.column(right_name)?.clone();
+                        value.rename(left_name);
+                        nulls.with_column(value)?;
+                    }
+                }
+                let row_df = self.drop_merged_right_on(row_df)?;
+                hstack_suffixed(nulls, row_df, &self.suffix)
+            },
+        }
+    }
+
+    /// Whether this join type merges each equi-join key pair into a single physical
+    /// column (the default), as opposed to keeping both sides' columns around
+    /// separately -- mirroring `det_join_schema`'s `JoinType::Outer{coalesce: false}`
+    /// special case.
+    fn merges_join_keys(&self) -> bool {
+        !matches!(self.how, JoinType::Outer { coalesce: false })
+    }
+
+    /// Drops `df`'s right-side equi-join-key columns when this join merges keys,
+    /// mirroring `det_join_schema`'s "we only do this for the left hand side as the
+    /// right hand side is dropped" rule so the operator's physical output schema
+    /// matches the logical plan's declared `Join` schema.
+    fn drop_merged_right_on(&self, mut df: DataFrame) -> PolarsResult<DataFrame> {
+        if !self.merges_join_keys() {
+            return Ok(df);
+        }
+        for name in &self.right_on_names {
+            if df.get_column_names().contains(&name.as_str()) {
+                df = df.drop(name)?;
+            }
+        }
+        Ok(df)
+    }
+
+    fn emits_unmatched(&self, which: JoinSide) -> bool {
+        matches!(
+            (&self.how, which),
+            (JoinType::Left, JoinSide::Left)
+                | (JoinType::Right, JoinSide::Right)
+                | (JoinType::Outer { .. }, _)
+        )
+    }
+
+    /// Evicts rows of `which` side's build table whose ordered value is older than
+    /// `min_relevant`, because the interval predicate guarantees they can never match a
+    /// future probe row from the other side. Unmatched rows are flushed (padded with
+    /// nulls) before being evicted rather than silently dropped, but only for the side
+    /// `which`'s join type actually emits unmatched rows for (see `emits_unmatched`).
+    fn prune_and_flush(&mut self, which: JoinSide, min_relevant: i64) -> PolarsResult<Vec<DataFrame>> {
+        let emit_unmatched = self.emits_unmatched(which);
+        let build = match which {
+            JoinSide::Left => &mut self.left,
+            JoinSide::Right => &mut self.right,
+        };
+
+        let mut aged_out = Vec::new();
+        build.table.retain(|_, rows| {
+            while matches!(rows.front(), Some(r) if r.ordered_value.map_or(false, |v| v < min_relevant))
+            {
+                aged_out.push(rows.pop_front().unwrap());
+            }
+            !rows.is_empty()
+        });
+
+        aged_out
+            .iter()
+            .filter(|r| !r.matched && emit_unmatched)
+            .map(|r| self.pad_with_nulls(which, r))
+            .collect()
+    }
+
+    /// Marks the left input exhausted and flushes every row still waiting in the right
+    /// table, since no further left probe can ever reach them.
+    pub fn mark_left_exhausted(&mut self) -> PolarsResult<Vec<DataFrame>> {
+        self.left.exhausted = true;
+        self.drain_unmatched(JoinSide::Right)
+    }
+
+    /// Marks the right input exhausted and flushes every row still waiting in the left
+    /// table, since no further right probe can ever reach them.
+    pub fn mark_right_exhausted(&mut self) -> PolarsResult<Vec<DataFrame>> {
+        self.right.exhausted = true;
+        self.drain_unmatched(JoinSide::Left)
+    }
+
+    /// Final call once both sides are exhausted: flushes anything still outstanding on
+    /// either side (a no-op if both `mark_*_exhausted` calls already drained them).
+    pub fn finalize(&mut self) -> PolarsResult<Vec<DataFrame>> {
+        let mut out = self.drain_unmatched(JoinSide::Left)?;
+        out.extend(self.drain_unmatched(JoinSide::Right)?);
+        Ok(out)
+    }
+
+    fn drain_unmatched(&mut self, which: JoinSide) -> PolarsResult<Vec<DataFrame>> {
+        if !self.emits_unmatched(which) {
+            let build = match which {
+                JoinSide::Left => &mut self.left,
+                JoinSide::Right => &mut self.right,
+            };
+            build.table.clear();
+            return Ok(Vec::new());
+        }
+
+        let build = match which {
+            JoinSide::Left => &mut self.left,
+            JoinSide::Right => &mut self.right,
+        };
+        let mut drained = Vec::new();
+        for rows in build.table.values_mut() {
+            while let Some(row) = rows.pop_front() {
+                if !row.matched {
+                    drained.push(row);
+                }
+            }
+        }
+        build.table.clear();
+        drained
+            .iter()
+            .map(|row| self.pad_with_nulls(which, row))
+            .collect()
+    }
+
+    pub fn fmt(&self) -> &str {
+        "symmetric_hash_join"
+    }
+}
+
+/// Horizontally stacks `left` and `right`, suffixing any of `right`'s column names that
+/// collide with one already in `left` -- mirroring `det_join_schema`'s suffix collision
+/// handling at the physical-execution layer.
+fn hstack_suffixed(left: DataFrame, mut right: DataFrame, suffix: &str) -> PolarsResult<DataFrame> {
+    let left_names = left.get_column_names_owned();
+    for name in left_names {
+        if right.get_column_names().contains(&name.as_str()) {
+            let new_name = format_smartstring!("{}{}", name, suffix);
+            right.rename(&name, new_name.as_str())?;
+        }
+    }
+    left.hstack(right.get_columns())
+}
+
+/// Names of `schema`'s columns at positions `on`, in `on`'s own order -- callers zip
+/// `left_on_names`/`right_on_names` pairwise, so this must preserve each key's pairing
+/// with its counterpart on the other side, not just its position within the schema.
+fn on_names(schema: &Schema, on: &[usize]) -> Vec<SmartString> {
+    on.iter()
+        .map(|&i| schema.get_at_index(i).unwrap().0.clone())
+        .collect()
+}
+
+/// Builds a single-height, all-null `DataFrame` matching `schema`, used to pad out an
+/// unmatched row's missing side for Left/Right/Outer semantics.
+fn null_frame(schema: &Schema, height: usize) -> DataFrame {
+    let columns = schema
+        .iter()
+        .map(|(name, dtype)| Series::full_null(name, height, dtype))
+        .collect();
+    DataFrame::new_no_checks(columns)
+}
+
+/// Integration point for the streaming lowering: return `true` when both join inputs are
+/// unbounded and the predicate carries a recognized bounded-interval condition on an
+/// ordered column, in which case the planner should pick [`SymmetricJoinOperator`]
+/// instead of the blocking hash join.
+pub fn should_use_symmetric_join(
+    left_unbounded: bool,
+    right_unbounded: bool,
+    ordered_on: &Option<(usize, usize)>,
+) -> bool {
+    left_unbounded && right_unbounded && ordered_on.is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn null_keys_never_match() {
+        assert!(!keys_match(&[AnyValue::Null], &[AnyValue::Null]));
+        assert!(!keys_match(&[AnyValue::Int64(1)], &[AnyValue::Null]));
+        assert!(keys_match(&[AnyValue::Int64(1)], &[AnyValue::Int64(1)]));
+        assert!(!keys_match(&[AnyValue::Int64(1)], &[AnyValue::Int64(2)]));
+    }
+}
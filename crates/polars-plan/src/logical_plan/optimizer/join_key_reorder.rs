@@ -0,0 +1,176 @@
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// Reorders a `Join`'s `left_on`/`right_on` key pairs so they line up with an input
+/// that is already sorted on those keys (in a different order), letting a downstream
+/// sort-merge/streaming join skip re-sorting that input. The existing order is read
+/// either from an explicit `Sort` node directly above the join or from a `Scan`'s own
+/// `FileInfo::sorted_by` metadata (see [`sorted_key_permutation`]).
+///
+/// Must run after predicate/projection pushdown: pushdown can change which node ends up
+/// feeding the join directly, and therefore whether a `Sort` is still visible
+/// immediately above it.
+pub struct JoinKeyReorderRule {}
+
+impl JoinKeyReorderRule {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizationRule for JoinKeyReorderRule {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> PolarsResult<Option<ALogicalPlan>> {
+        let ALogicalPlan::Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            schema,
+        } = lp_arena.get(node)
+        else {
+            return Ok(None);
+        };
+
+        // Only equi-joins where key order carries no semantic meaning are safe to
+        // permute; e.g. an as-of join's key order matters for tie-breaking.
+        if !matches!(
+            options.args.how,
+            JoinType::Inner | JoinType::Left | JoinType::Outer { .. }
+        ) {
+            return Ok(None);
+        }
+
+        let permutation = sorted_key_permutation(*input_left, left_on, lp_arena, expr_arena)
+            .or_else(|| sorted_key_permutation(*input_right, right_on, lp_arena, expr_arena));
+
+        let Some(permutation) = permutation else {
+            return Ok(None);
+        };
+        if permutation.iter().enumerate().all(|(i, p)| i == *p) {
+            // Already in the order that best matches the sorted input.
+            return Ok(None);
+        }
+
+        let new_left_on = permute(left_on, &permutation);
+        let new_right_on = permute(right_on, &permutation);
+
+        Ok(Some(ALogicalPlan::Join {
+            input_left: *input_left,
+            input_right: *input_right,
+            left_on: new_left_on,
+            right_on: new_right_on,
+            options: options.clone(),
+            schema: schema.clone(),
+        }))
+    }
+}
+
+fn permute(keys: &[Node], permutation: &[usize]) -> Vec<Node> {
+    permutation.iter().map(|&i| keys[i]).collect()
+}
+
+/// If `input`'s existing ordering is known to be exactly a permutation of `keys`,
+/// returns that permutation: `result[i]` is the index into `keys` of the key that
+/// should move to position `i` so the join key order matches that existing order.
+///
+/// Two sources of known ordering are consulted: an explicit `Sort` node immediately
+/// above the join, and (absent that) a `Scan`'s own `FileInfo::sorted_by` — a source
+/// can already be known-sorted (e.g. from the file format's metadata) with no `Sort`
+/// node anywhere in the plan.
+fn sorted_key_permutation(
+    input: Node,
+    keys: &[Node],
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<Vec<usize>> {
+    let sort_names: Vec<&str> = match lp_arena.get(input) {
+        ALogicalPlan::Sort { by_column, .. } => {
+            if by_column.len() != keys.len() {
+                return None;
+            }
+            by_column
+                .iter()
+                .map(|node| column_name(*node, expr_arena))
+                .collect::<Option<_>>()?
+        },
+        ALogicalPlan::Scan { file_info, .. } if file_info.sorted_by.len() == keys.len() => {
+            file_info.sorted_by.iter().map(|s| s.as_str()).collect()
+        },
+        _ => return None,
+    };
+
+    let key_names: Vec<&str> = keys
+        .iter()
+        .map(|node| column_name(*node, expr_arena))
+        .collect::<Option<_>>()?;
+
+    permutation_from_names(&key_names, &sort_names)
+}
+
+/// Pure name-matching half of [`sorted_key_permutation`], split out so it can be
+/// exercised directly with plain strings without needing an `Arena`/`ALogicalPlan`.
+///
+/// Returns `None` unless the result is a true bijection over `0..key_names.len()` — a
+/// repeated column name in either list would otherwise let [`position`](slice::position)
+/// map two sort columns onto the same join key, silently dropping one key from the
+/// permuted output and duplicating another.
+fn permutation_from_names(key_names: &[&str], sort_names: &[&str]) -> Option<Vec<usize>> {
+    if sort_names.len() != key_names.len() {
+        return None;
+    }
+    let mut permutation = Vec::with_capacity(key_names.len());
+    let mut used = vec![false; key_names.len()];
+    for sort_name in sort_names {
+        let pos = key_names.iter().position(|name| name == sort_name)?;
+        if used[pos] {
+            // Already mapped to an earlier sort column: `key_names` (or `sort_names`)
+            // has a duplicate, so there's no valid 1:1 permutation.
+            return None;
+        }
+        used[pos] = true;
+        permutation.push(pos);
+    }
+    Some(permutation)
+}
+
+fn column_name<'a>(node: Node, expr_arena: &'a Arena<AExpr>) -> Option<&'a str> {
+    match expr_arena.get(node) {
+        AExpr::Column(name) => Some(name.as_ref()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_permutation_for_reordered_keys() {
+        let keys = ["a", "b", "c"];
+        let sort = ["c", "a", "b"];
+        assert_eq!(permutation_from_names(&keys, &sort), Some(vec![2, 0, 1]));
+    }
+
+    #[test]
+    fn rejects_duplicate_join_key_instead_of_silently_permuting() {
+        // `a` appears twice among the join keys: matching both sort columns against it
+        // would otherwise drop `b` from the permutation and duplicate `a`.
+        let keys = ["a", "a"];
+        let sort = ["a", "b"];
+        assert_eq!(permutation_from_names(&keys, &sort), None);
+    }
+
+    #[test]
+    fn rejects_when_sort_name_is_not_a_join_key() {
+        let keys = ["a", "b"];
+        let sort = ["a", "c"];
+        assert_eq!(permutation_from_names(&keys, &sort), None);
+    }
+}
@@ -0,0 +1,3 @@
+mod join_key_reorder;
+
+pub use join_key_reorder::JoinKeyReorderRule;
@@ -3,6 +3,7 @@ use std::path::Path;
 
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
+use polars_core::utils::get_supertype;
 use polars_utils::format_smartstring;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -16,7 +17,7 @@ impl LogicalPlan {
             Scan { file_info, .. } => Ok(Cow::Borrowed(&file_info.schema)),
             #[cfg(feature = "python")]
             PythonScan { options } => Ok(Cow::Borrowed(&options.schema)),
-            Union { inputs, .. } => inputs[0].schema(),
+            Union { inputs, .. } => Ok(Cow::Owned(union_schema(inputs)?)),
             #[cfg(feature = "horizontal_concat")]
             HConcat { schema, .. } => Ok(Cow::Borrowed(schema)),
             Cache { input, .. } => input.schema(),
@@ -55,6 +56,34 @@ pub struct FileInfo {
     /// - estimated size
     pub row_estimation: (Option<usize>, usize),
     pub hive_parts: Option<Arc<hive::HivePartitions>>,
+    /// Optional relation alias (e.g. the `AS` name in a `FROM ... AS alias`, or a name
+    /// assigned for self-join disambiguation), used to resolve `qualifier.column`
+    /// references without relying on a mangled/suffixed column name.
+    pub qualifier: Option<Arc<str>>,
+    /// Per-column statistics (e.g. sourced from Parquet column statistics at scan time),
+    /// used to compute predicate-specific selectivity instead of a fixed heuristic.
+    /// Absent when the source doesn't expose statistics.
+    pub column_stats: Option<PlHashMap<SmartString, ColumnStatistics>>,
+    /// Columns this source is already known to be sorted by, outermost-first (e.g.
+    /// reported by the file format's own metadata), independent of whether an explicit
+    /// `Sort` node appears above this scan in the plan. Empty when the source's
+    /// ordering isn't known.
+    pub sorted_by: Vec<SmartString>,
+}
+
+/// Summary statistics for a single column, used for selectivity estimation.
+///
+/// `min`/`max` are stored as `f64` so that both numeric and temporal columns (cast to
+/// their physical integer/float representation) can share one representation; this is a
+/// deliberate simplification, not a general-purpose stats format.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ColumnStatistics {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub null_count: Option<usize>,
+    /// Estimated number of distinct values ("ndv").
+    pub distinct_count: Option<usize>,
 }
 
 impl FileInfo {
@@ -68,9 +97,37 @@ impl FileInfo {
             reader_schema,
             row_estimation,
             hive_parts: None,
+            qualifier: None,
+            column_stats: None,
+            sorted_by: Vec::new(),
         }
     }
 
+    /// Attaches a relation alias to this scan, so join schema resolution can later
+    /// address its columns as `qualifier.column`.
+    pub fn with_qualifier(mut self, qualifier: Option<Arc<str>>) -> Self {
+        self.qualifier = qualifier;
+        self
+    }
+
+    /// Attaches per-column statistics (e.g. parsed from Parquet column statistics),
+    /// enabling selectivity-driven row estimation for predicates over this scan.
+    pub fn with_column_stats(
+        mut self,
+        column_stats: Option<PlHashMap<SmartString, ColumnStatistics>>,
+    ) -> Self {
+        self.column_stats = column_stats;
+        self
+    }
+
+    /// Records that this source is already sorted by `sorted_by` (outermost-first), so
+    /// planning can skip an otherwise-needed re-sort (see
+    /// `optimizer::JoinKeyReorderRule`) without requiring an explicit `Sort` node.
+    pub fn with_sorted_by(mut self, sorted_by: Vec<SmartString>) -> Self {
+        self.sorted_by = sorted_by;
+        self
+    }
+
     /// Updates the statistics and merges the hive partitions schema with the file one.
     pub fn init_hive_partitions(&mut self, url: &Path) -> PolarsResult<()> {
         self.hive_parts = hive::HivePartitions::parse_url(url).map(|hive_parts| {
@@ -106,6 +163,60 @@ impl FileInfo {
     }
 }
 
+/// Options carried on a `LogicalPlan::PythonScan`.
+#[cfg(feature = "python")]
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PythonScanOptions {
+    pub schema: SchemaRef,
+    pub with_columns: Option<Arc<[String]>>,
+    /// A user-supplied row-count hint, see [`PythonScanCardinality`].
+    pub cardinality: Option<PythonScanCardinality>,
+    /// Whether the Python source can apply predicate pushdown; if so, it is expected to
+    /// refine `cardinality` to a post-filter estimate via
+    /// [`refine_python_scan_cardinality`].
+    pub supports_predicate_pushdown: bool,
+}
+
+/// A row-count hint a Python data source can supply for a `PythonScan`, so planning
+/// doesn't have to treat it as unbounded (`usize::MAX`) by default.
+#[cfg(feature = "python")]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PythonScanCardinality {
+    /// The source knows its exact row count (e.g. a `DataFrame` already in memory).
+    Exact(usize),
+    /// The source can only give an estimate (e.g. a lazily-evaluated generator).
+    Estimate(usize),
+}
+
+#[cfg(feature = "python")]
+impl PythonScanCardinality {
+    fn as_row_estimation(&self) -> (Option<usize>, usize) {
+        match self {
+            Self::Exact(n) => (Some(*n), *n),
+            Self::Estimate(n) => (None, *n),
+        }
+    }
+}
+
+/// Updates a `PythonScan`'s cardinality hint once predicate pushdown has handed the
+/// source a predicate it can evaluate: sources that advertise
+/// `supports_predicate_pushdown` are expected to report a post-filter estimate here
+/// rather than just their unfiltered size, the same way [`estimate_predicate_selectivity`]
+/// narrows a file scan's estimate using column statistics.
+#[cfg(feature = "python")]
+pub fn refine_python_scan_cardinality(
+    options: &mut PythonScanOptions,
+    post_filter_estimate: PythonScanCardinality,
+) {
+    if options.supports_predicate_pushdown {
+        options.cardinality = Some(post_filter_estimate);
+    }
+}
+
+/// Fallback heuristic used when a predicate can't be evaluated against column
+/// statistics: each filter is assumed to keep 90% of its input.
 #[cfg(feature = "streaming")]
 fn estimate_sizes(
     known_size: Option<usize>,
@@ -122,6 +233,134 @@ fn estimate_sizes(
     }
 }
 
+/// Flips a comparison operator to account for operand order, so a caller that always
+/// evaluates `column OP literal` can handle a predicate written as `literal OP column`
+/// by swapping the operands and mirroring the operator (`<` <-> `>`, `<=` <-> `>=`);
+/// `=`/`!=` are symmetric and pass through unchanged.
+#[cfg(feature = "streaming")]
+fn mirror_comparison_op(op: Operator) -> Operator {
+    match op {
+        Operator::Lt => Operator::Gt,
+        Operator::LtEq => Operator::GtEq,
+        Operator::Gt => Operator::Lt,
+        Operator::GtEq => Operator::LtEq,
+        other => other,
+    }
+}
+
+/// Selectivity of a single comparison against one column's statistics.
+#[cfg(feature = "streaming")]
+fn column_comparison_selectivity(
+    stats: &ColumnStatistics,
+    op: Operator,
+    literal: f64,
+) -> Option<f32> {
+    match op {
+        Operator::Eq => {
+            let ndv = stats.distinct_count?;
+            Some(if ndv == 0 { 0.0 } else { (1.0 / ndv as f32).clamp(0.0, 1.0) })
+        },
+        Operator::NotEq => {
+            let ndv = stats.distinct_count?;
+            Some(if ndv == 0 { 1.0 } else { (1.0 - 1.0 / ndv as f32).clamp(0.0, 1.0) })
+        },
+        Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => {
+            let (min, max) = (stats.min?, stats.max?);
+            if max <= min {
+                return Some(1.0);
+            }
+            let covered = match op {
+                Operator::Lt | Operator::LtEq => literal - min,
+                Operator::Gt | Operator::GtEq => max - literal,
+                _ => unreachable!(),
+            };
+            Some((covered / (max - min)).clamp(0.0, 1.0) as f32)
+        },
+        _ => None,
+    }
+}
+
+#[cfg(feature = "streaming")]
+fn column_is_null_selectivity(stats: &ColumnStatistics, row_count: usize) -> Option<f32> {
+    if row_count == 0 {
+        return Some(0.0);
+    }
+    stats
+        .null_count
+        .map(|null_count| (null_count as f32 / row_count as f32).clamp(0.0, 1.0))
+}
+
+/// Estimates the selectivity (fraction of rows a predicate keeps) using per-column
+/// statistics on `file_info`: `col = v` uses `1 / ndv`, range comparisons use the
+/// fraction of `[min, max]` the literal covers, `col IS NULL` uses `null_count /
+/// row_count`. Conjuncts (`AND`) combine multiplicatively; disjuncts (`OR`) combine via
+/// inclusion-exclusion (`p(a) + p(b) - p(a) * p(b)`). Returns `None` as soon as any
+/// sub-expression can't be evaluated this way (missing stats, predicate shape we don't
+/// recognize), so the caller can fall back to [`estimate_sizes`]'s fixed heuristic.
+#[cfg(feature = "streaming")]
+fn estimate_predicate_selectivity(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    file_info: &FileInfo,
+    row_count: usize,
+) -> Option<f32> {
+    let column_stats = file_info.column_stats.as_ref()?;
+
+    fn as_column_name<'a>(expr_arena: &'a Arena<AExpr>, node: Node) -> Option<&'a str> {
+        match expr_arena.get(node) {
+            AExpr::Column(name) => Some(name.as_ref()),
+            _ => None,
+        }
+    }
+
+    fn as_literal_f64(expr_arena: &Arena<AExpr>, node: Node) -> Option<f64> {
+        match expr_arena.get(node) {
+            AExpr::Literal(lv) => lv.to_any_value()?.extract::<f64>(),
+            _ => None,
+        }
+    }
+
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr { left, op, right } => match op {
+            Operator::And | Operator::LogicalAnd => {
+                let l = estimate_predicate_selectivity(*left, expr_arena, file_info, row_count)?;
+                let r = estimate_predicate_selectivity(*right, expr_arena, file_info, row_count)?;
+                Some((l * r).clamp(0.0, 1.0))
+            },
+            Operator::Or | Operator::LogicalOr => {
+                let l = estimate_predicate_selectivity(*left, expr_arena, file_info, row_count)?;
+                let r = estimate_predicate_selectivity(*right, expr_arena, file_info, row_count)?;
+                Some((l + r - l * r).clamp(0.0, 1.0))
+            },
+            Operator::Eq | Operator::NotEq | Operator::Lt | Operator::LtEq | Operator::Gt
+            | Operator::GtEq => {
+                // `col OP literal` keeps `op` as-is; `literal OP col` is the same
+                // comparison with the operand order flipped, so the operator must be
+                // mirrored (e.g. `100 < price` means `price > 100`, not `price < 100`).
+                let (col, op, literal) = match (
+                    as_column_name(expr_arena, *left),
+                    as_column_name(expr_arena, *right),
+                ) {
+                    (Some(name), None) => (name, *op, as_literal_f64(expr_arena, *right)?),
+                    (None, Some(name)) => {
+                        (name, mirror_comparison_op(*op), as_literal_f64(expr_arena, *left)?)
+                    },
+                    _ => return None,
+                };
+                column_comparison_selectivity(column_stats.get(col)?, op, literal)
+            },
+            _ => None,
+        },
+        // `col.is_null()` lowers to a named function application; we match on it
+        // structurally rather than importing every function-expression variant.
+        AExpr::Function { input, function, .. } if format!("{function:?}") == "IsNull" => {
+            let name = as_column_name(expr_arena, *input.first()?)?;
+            column_is_null_selectivity(column_stats.get(name)?, row_count)
+        },
+        _ => None,
+    }
+}
+
 #[cfg(feature = "streaming")]
 pub fn set_estimated_row_counts(
     root: Node,
@@ -141,12 +380,70 @@ pub fn set_estimated_row_counts(
 
     match lp_arena.get(root) {
         Selection { predicate, input } => {
-            _filter_count += expr_arena
-                .iter(*predicate)
-                .filter(|(_, ae)| matches!(ae, AExpr::BinaryExpr { .. }))
-                .count()
-                + 1;
-            set_estimated_row_counts(*input, lp_arena, expr_arena, _filter_count, scratch)
+            // When the input is a direct scan with column statistics, compute the
+            // predicate's actual selectivity instead of the fixed 0.9^n heuristic.
+            let stats_selectivity = match lp_arena.get(*input) {
+                Scan { file_info, .. } if file_info.column_stats.is_some() => {
+                    let (_, estimated_size) = file_info.row_estimation;
+                    estimate_predicate_selectivity(
+                        *predicate,
+                        expr_arena,
+                        file_info,
+                        estimated_size,
+                    )
+                },
+                _ => None,
+            };
+
+            match stats_selectivity {
+                Some(selectivity) => {
+                    let mut out = set_estimated_row_counts(
+                        *input,
+                        lp_arena,
+                        expr_arena,
+                        _filter_count,
+                        scratch,
+                    );
+                    out.0 = None;
+                    out.1 = (out.1 as f32 * selectivity) as usize;
+                    out
+                },
+                None => {
+                    let filter_count_here = expr_arena
+                        .iter(*predicate)
+                        .filter(|(_, ae)| matches!(ae, AExpr::BinaryExpr { .. }))
+                        .count()
+                        + 1;
+
+                    // A `PythonScan` has no column statistics to drive
+                    // `estimate_predicate_selectivity`, but a source that advertises
+                    // `supports_predicate_pushdown` can still have its cardinality hint
+                    // refined with the same 0.9^n heuristic applied elsewhere, rather
+                    // than leaving it to report its unfiltered (or `usize::MAX`) size.
+                    #[cfg(feature = "python")]
+                    if matches!(lp_arena.get(*input), PythonScan { options } if options.supports_predicate_pushdown)
+                    {
+                        if let PythonScan { mut options } = lp_arena.take(*input) {
+                            let (known_size, estimated_size) = options
+                                .cardinality
+                                .as_ref()
+                                .map(PythonScanCardinality::as_row_estimation)
+                                .unwrap_or((None, usize::MAX));
+                            let (_, refined_estimate) =
+                                estimate_sizes(known_size, estimated_size, filter_count_here);
+                            refine_python_scan_cardinality(
+                                &mut options,
+                                PythonScanCardinality::Estimate(refined_estimate),
+                            );
+                            lp_arena.replace(*input, PythonScan { options });
+                            return (None, refined_estimate, 0);
+                        }
+                    }
+
+                    _filter_count += filter_count_here;
+                    set_estimated_row_counts(*input, lp_arena, expr_arena, _filter_count, scratch)
+                },
+            }
         },
         Slice { input, len, .. } => {
             let len = *len as usize;
@@ -250,9 +547,14 @@ pub fn set_estimated_row_counts(
             (known_size, estimated_size, _filter_count)
         },
         #[cfg(feature = "python")]
-        PythonScan { .. } => {
-            // TODO! get row estimation.
-            (None, usize::MAX, _filter_count)
+        PythonScan { options } => match &options.cardinality {
+            // A user-supplied hint lets us avoid poisoning downstream size estimates
+            // (join build-side selection, slice pushdown) with `usize::MAX`.
+            Some(cardinality) => {
+                let (known_size, estimated_size) = cardinality.as_row_estimation();
+                (known_size, estimated_size, _filter_count)
+            },
+            None => (None, usize::MAX, _filter_count),
         },
         lp => {
             lp.copy_inputs(scratch);
@@ -272,6 +574,121 @@ pub fn set_estimated_row_counts(
     }
 }
 
+/// Computes the schema of a `Union` by coercing every input's schema to a common,
+/// type-widened schema instead of blindly trusting the first input.
+///
+/// Columns are matched positionally (mirroring how the executor aligns union branches),
+/// and for each column the least-upper-bound "supertype" across all inputs is taken, so
+/// e.g. an `Int32` branch unioned with an `Int64` branch yields an `Int64` output column.
+/// The executor is expected to insert casts to this schema on each branch.
+fn union_schema(inputs: &[LogicalPlan]) -> PolarsResult<SchemaRef> {
+    let mut schemas = Vec::with_capacity(inputs.len());
+    for lp in inputs {
+        schemas.push(lp.schema()?.into_owned());
+    }
+    coerce_union_schemas(&schemas)
+}
+
+/// Pure dtype-coercion half of [`union_schema`], split out so it can be exercised
+/// directly against `Schema` values without needing a `LogicalPlan` per input.
+fn coerce_union_schemas(schemas: &[SchemaRef]) -> PolarsResult<SchemaRef> {
+    let mut schemas = schemas.iter();
+    let first = schemas
+        .next()
+        .ok_or_else(|| polars_err!(ComputeError: "'union' requires at least one input"))?;
+
+    let names: Vec<SmartString> = first.iter().map(|(name, _)| name.clone()).collect();
+    let mut dtypes: Vec<DataType> = first.iter().map(|(_, dtype)| dtype.clone()).collect();
+
+    for (i, schema) in schemas.enumerate() {
+        polars_ensure!(
+            schema.len() == dtypes.len(),
+            ComputeError: "'union' inputs should all have the same number of columns, \
+            got {} in the first input and {} in input {}", dtypes.len(), schema.len(), i + 1
+        );
+        for (col_idx, (_name, dtype)) in schema.iter().enumerate() {
+            dtypes[col_idx] = get_supertype(&dtypes[col_idx], dtype).ok_or_else(|| polars_err!(
+                ComputeError: "'union' got incompatible dtypes for column '{}': '{}' and '{}'",
+                names[col_idx], dtypes[col_idx], dtype
+            ))?;
+        }
+    }
+
+    let new_schema: Schema = names
+        .into_iter()
+        .zip(dtypes)
+        .map(|(name, dtype)| Field::new(&name, dtype))
+        .collect();
+    Ok(Arc::new(new_schema))
+}
+
+/// A table-qualified column reference, e.g. `right.price`. `qualifier` is `None` for
+/// columns coming from a relation that was never given an alias.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct QualifiedName {
+    pub qualifier: Option<Arc<str>>,
+    pub name: SmartString,
+}
+
+impl QualifiedName {
+    fn new(qualifier: Option<Arc<str>>, name: SmartString) -> Self {
+        Self { qualifier, name }
+    }
+}
+
+/// The result of joining two schemas: the physical (flat, uniquely-named) output schema,
+/// plus a side-table that lets expression binding resolve a `qualifier.column` reference
+/// to its physical column name without needing to know whether that column happened to
+/// be suffix-mangled.
+#[derive(Clone, Debug)]
+pub struct JoinSchema {
+    pub schema: SchemaRef,
+    pub qualified_names: PlHashMap<QualifiedName, SmartString>,
+}
+
+/// Resolves a (possibly qualified) column reference against a join's output schema.
+///
+/// An exact `(qualifier, name)` match always wins. Without a qualifier (or when the
+/// qualifier is unknown to this join), falls back to a bare-name match, which is only
+/// valid when exactly one physical column in `qualified_names` carries that bare name;
+/// a bare reference that matches columns from both sides is rejected as ambiguous.
+pub(crate) fn resolve_join_column<'a>(
+    name: &str,
+    qualifier: Option<&str>,
+    join_schema: &'a JoinSchema,
+) -> PolarsResult<&'a SmartString> {
+    if let Some(qualifier) = qualifier {
+        let key = QualifiedName::new(Some(Arc::from(qualifier)), name.into());
+        if let Some(physical) = join_schema.qualified_names.get(&key) {
+            return Ok(physical);
+        }
+        let qualifier_known = join_schema
+            .qualified_names
+            .keys()
+            .any(|qn| qn.qualifier.as_deref() == Some(qualifier));
+        polars_ensure!(
+            !qualifier_known,
+            ColumnNotFound: "no column '{}' found for relation '{}'", name, qualifier
+        );
+    }
+
+    let mut matches = join_schema
+        .qualified_names
+        .iter()
+        .filter(|(qn, _)| qn.name == name);
+    let Some((_, physical)) = matches.next() else {
+        polars_bail!(ColumnNotFound: "no column named '{}' found", name);
+    };
+    polars_ensure!(
+        matches.next().is_none(),
+        ComputeError: "column '{}' is ambiguous: it exists on both sides of the join, \
+        qualify it as e.g. 'left_relation.{}'", name, name
+    );
+    Ok(physical)
+}
+
+/// Existing, unqualified entry point: callers that don't care about table-qualified
+/// resolution (the vast majority) keep building a plain schema, unchanged.
 pub(crate) fn det_join_schema(
     schema_left: &SchemaRef,
     schema_right: &SchemaRef,
@@ -279,16 +696,64 @@ pub(crate) fn det_join_schema(
     right_on: &[Expr],
     options: &JoinOptions,
 ) -> PolarsResult<SchemaRef> {
+    Ok(det_join_schema_qualified(
+        schema_left,
+        schema_right,
+        left_on,
+        right_on,
+        options,
+        None,
+        None,
+    )?
+    .schema)
+}
+
+/// Same as [`det_join_schema`], but also returns a [`JoinSchema::qualified_names`] side
+/// table so callers that know each side's relation alias can later resolve
+/// `qualifier.column` references via [`resolve_join_column`].
+///
+/// Scope: a qualifier can currently only be supplied for a `Scan` (via its
+/// `FileInfo::qualifier`). `DataFrameScan` has no qualifier field of its own, so pass
+/// `None` for a side backed by one; qualified resolution against an in-memory relation
+/// is not supported yet and needs that field added before it can be.
+pub(crate) fn det_join_schema_qualified(
+    schema_left: &SchemaRef,
+    schema_right: &SchemaRef,
+    left_on: &[Expr],
+    right_on: &[Expr],
+    options: &JoinOptions,
+    left_qualifier: Option<Arc<str>>,
+    right_qualifier: Option<Arc<str>>,
+) -> PolarsResult<JoinSchema> {
     match options.args.how {
         // semi and anti joins are just filtering operations
         // the schema will never change.
         #[cfg(feature = "semi_anti_join")]
-        JoinType::Semi | JoinType::Anti => Ok(schema_left.clone()),
+        JoinType::Semi | JoinType::Anti => {
+            let qualified_names = schema_left
+                .iter_names()
+                .map(|name| {
+                    (
+                        QualifiedName::new(left_qualifier.clone(), name.clone()),
+                        name.clone(),
+                    )
+                })
+                .collect();
+            Ok(JoinSchema {
+                schema: schema_left.clone(),
+                qualified_names,
+            })
+        },
         _ => {
             let mut new_schema = Schema::with_capacity(schema_left.len() + schema_right.len());
+            let mut qualified_names = PlHashMap::with_capacity(schema_left.len() + schema_right.len());
 
             for (name, dtype) in schema_left.iter() {
                 new_schema.with_column(name.clone(), dtype.clone());
+                qualified_names.insert(
+                    QualifiedName::new(left_qualifier.clone(), name.clone()),
+                    name.clone(),
+                );
             }
 
             // make sure that expression are assigned to the schema
@@ -351,14 +816,178 @@ pub(crate) fn det_join_schema(
                         }
 
                         let new_name = format_smartstring!("{}{}", name, options.args.suffix());
+                        qualified_names.insert(
+                            QualifiedName::new(right_qualifier.clone(), name.clone()),
+                            new_name.clone(),
+                        );
                         new_schema.with_column(new_name, dtype.clone());
                     } else {
+                        qualified_names.insert(
+                            QualifiedName::new(right_qualifier.clone(), name.clone()),
+                            name.clone(),
+                        );
                         new_schema.with_column(name.clone(), dtype.clone());
                     }
                 }
             }
 
-            Ok(Arc::new(new_schema))
+            Ok(JoinSchema {
+                schema: Arc::new(new_schema),
+                qualified_names,
+            })
         },
     }
 }
+
+#[cfg(test)]
+mod union_schema_tests {
+    use super::*;
+
+    fn schema(fields: &[(&str, DataType)]) -> SchemaRef {
+        Arc::new(
+            fields
+                .iter()
+                .map(|(name, dtype)| Field::new(name, dtype.clone()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn widens_to_common_supertype() {
+        let a = schema(&[("a", DataType::Int32), ("b", DataType::Float32)]);
+        let b = schema(&[("a", DataType::Int64), ("b", DataType::Float64)]);
+        let coerced = coerce_union_schemas(&[a, b]).unwrap();
+        assert_eq!(coerced.get("a").unwrap(), &DataType::Int64);
+        assert_eq!(coerced.get("b").unwrap(), &DataType::Float64);
+    }
+
+    #[test]
+    fn errors_on_column_count_mismatch() {
+        let a = schema(&[("a", DataType::Int32)]);
+        let b = schema(&[("a", DataType::Int32), ("b", DataType::Int32)]);
+        assert!(coerce_union_schemas(&[a, b]).is_err());
+    }
+
+    #[test]
+    fn errors_on_incompatible_dtypes() {
+        let a = schema(&[("a", DataType::Struct(vec![]))]);
+        let b = schema(&[("a", DataType::List(Box::new(DataType::Int32)))]);
+        assert!(coerce_union_schemas(&[a, b]).is_err());
+    }
+}
+
+#[cfg(test)]
+mod join_qualifier_tests {
+    use super::*;
+
+    fn join_schema(entries: &[(Option<&str>, &str, &str, DataType)]) -> JoinSchema {
+        let mut schema = Schema::with_capacity(entries.len());
+        let mut qualified_names = PlHashMap::with_capacity(entries.len());
+        for (qualifier, name, physical, dtype) in entries {
+            schema.with_column((*physical).into(), dtype.clone());
+            qualified_names.insert(
+                QualifiedName::new(qualifier.map(Arc::from), (*name).into()),
+                (*physical).into(),
+            );
+        }
+        JoinSchema {
+            schema: Arc::new(schema),
+            qualified_names,
+        }
+    }
+
+    #[test]
+    fn exact_qualifier_match_wins() {
+        let js = join_schema(&[
+            (Some("left"), "price", "price", DataType::Int64),
+            (Some("right"), "price", "price_right", DataType::Int64),
+        ]);
+        assert_eq!(
+            resolve_join_column("price", Some("right"), &js).unwrap().as_str(),
+            "price_right"
+        );
+        assert_eq!(
+            resolve_join_column("price", Some("left"), &js).unwrap().as_str(),
+            "price"
+        );
+    }
+
+    #[test]
+    fn unqualified_bare_name_resolves_when_unambiguous() {
+        let js = join_schema(&[(Some("left"), "id", "id", DataType::Int64)]);
+        assert_eq!(resolve_join_column("id", None, &js).unwrap().as_str(), "id");
+    }
+
+    #[test]
+    fn unqualified_bare_name_is_ambiguous_across_sides() {
+        let js = join_schema(&[
+            (Some("left"), "price", "price", DataType::Int64),
+            (Some("right"), "price", "price_right", DataType::Int64),
+        ]);
+        assert!(resolve_join_column("price", None, &js).is_err());
+    }
+}
+
+#[cfg(all(test, feature = "streaming"))]
+mod predicate_selectivity_tests {
+    use super::*;
+
+    #[test]
+    fn mirror_comparison_op_flips_strict_and_inclusive() {
+        assert_eq!(mirror_comparison_op(Operator::Lt), Operator::Gt);
+        assert_eq!(mirror_comparison_op(Operator::Gt), Operator::Lt);
+        assert_eq!(mirror_comparison_op(Operator::LtEq), Operator::GtEq);
+        assert_eq!(mirror_comparison_op(Operator::GtEq), Operator::LtEq);
+        assert_eq!(mirror_comparison_op(Operator::Eq), Operator::Eq);
+        assert_eq!(mirror_comparison_op(Operator::NotEq), Operator::NotEq);
+    }
+
+    #[test]
+    fn literal_on_left_matches_mirrored_column_on_left() {
+        let stats = ColumnStatistics {
+            min: Some(0.0),
+            max: Some(100.0),
+            null_count: None,
+            distinct_count: None,
+        };
+        // `100 < price` (literal on the left) should score the same as the equivalent
+        // `price > 100` (column on the left), not the same as `price < 100`.
+        let literal_on_left =
+            column_comparison_selectivity(&stats, mirror_comparison_op(Operator::Lt), 100.0);
+        let column_on_left = column_comparison_selectivity(&stats, Operator::Gt, 100.0);
+        let unmirrored = column_comparison_selectivity(&stats, Operator::Lt, 100.0);
+        assert_eq!(literal_on_left, column_on_left);
+        assert_ne!(literal_on_left, unmirrored);
+    }
+}
+
+#[cfg(all(test, feature = "python"))]
+mod python_scan_cardinality_tests {
+    use super::*;
+
+    fn options(cardinality: Option<PythonScanCardinality>, supports_pushdown: bool) -> PythonScanOptions {
+        PythonScanOptions {
+            schema: Arc::new(Schema::default()),
+            with_columns: None,
+            cardinality,
+            supports_predicate_pushdown: supports_pushdown,
+        }
+    }
+
+    #[test]
+    fn as_row_estimation_distinguishes_exact_from_estimate() {
+        assert_eq!(PythonScanCardinality::Exact(10).as_row_estimation(), (Some(10), 10));
+        assert_eq!(PythonScanCardinality::Estimate(10).as_row_estimation(), (None, 10));
+    }
+
+    #[test]
+    fn refine_updates_cardinality_only_when_pushdown_supported() {
+        let mut opts = options(Some(PythonScanCardinality::Exact(100)), true);
+        refine_python_scan_cardinality(&mut opts, PythonScanCardinality::Estimate(42));
+        assert!(matches!(opts.cardinality, Some(PythonScanCardinality::Estimate(42))));
+
+        let mut opts = options(Some(PythonScanCardinality::Exact(100)), false);
+        refine_python_scan_cardinality(&mut opts, PythonScanCardinality::Estimate(42));
+        assert!(matches!(opts.cardinality, Some(PythonScanCardinality::Exact(100))));
+    }
+}